@@ -0,0 +1,105 @@
+#!/usr/bin/env python3
+# ============================================================================
+# Benchmark de rafală - IDS Scanner
+# ============================================================================
+# Trimite cât de repede poate N mesaje CEF prin UDP, de la S surse, și
+# urmărește memoria (RSS) procesului ids-scanner cât durează rafala.
+#
+# Utilizare:
+#   python3 bench_flood.py [--port 5555] [--mesaje 500000] [--surse 1000]
+#                          [--rata 50000] [--pid PID]
+#
+# La final, compară în log-ul scanner-ului (raportul "🧵 Pipeline") câte
+# mesaje au fost procesate și câte aruncate.
+# ============================================================================
+
+import argparse
+import socket
+import threading
+import time
+
+
+def rss_kb(pid):
+    """Memoria rezidentă a procesului (kB), din /proc/<pid>/status"""
+    try:
+        with open(f"/proc/{pid}/status") as f:
+            for linie in f:
+                if linie.startswith("VmRSS:"):
+                    return int(linie.split()[1])
+    except OSError:
+        pass
+    return 0
+
+
+def erori_buffer_udp():
+    """Pachete UDP aruncate de kernel (socket-ul nu a fost golit la timp)"""
+    try:
+        with open("/proc/net/snmp") as f:
+            linii = [l.split() for l in f if l.startswith("Udp:")]
+        return int(linii[1][linii[0].index("RcvbufErrors")])
+    except (OSError, ValueError, IndexError):
+        return 0
+
+
+def urmareste_memoria(pid, rezultat, gata):
+    while not gata.is_set():
+        rezultat["rss_maxim"] = max(rezultat["rss_maxim"], rss_kb(pid))
+        time.sleep(0.05)
+
+
+def main():
+    p = argparse.ArgumentParser(description="Rafală UDP către ids-scanner")
+    p.add_argument("--host", default="127.0.0.1")
+    p.add_argument("--port", type=int, default=5555)
+    p.add_argument("--mesaje", type=int, default=500_000)
+    p.add_argument("--surse", type=int, default=1000)
+    p.add_argument("--rata", type=int, default=0, help="mesaje/s (0 = fără limită)")
+    p.add_argument("--pid", type=int, help="PID-ul ids-scanner (pentru memorie)")
+    a = p.parse_args()
+
+    # Mesajele sunt pregătite dinainte: măsurăm scanner-ul, nu Python-ul
+    mesaje = [
+        (
+            f"<134>Oct 17 10:00:00 fw01 CEF:0|Test|FW|1.0|100|Deny|5|"
+            f"src=10.{(i % a.surse) // 256}.{(i % a.surse) % 256}.1 "
+            f"dst=192.168.{(i // 7) % 256}.{i % 250 + 1} dpt={i % 1024 + 1} act=deny"
+        ).encode()
+        for i in range(a.mesaje)
+    ]
+
+    rezultat = {"rss_maxim": 0}
+    gata = threading.Event()
+    if a.pid:
+        rss_initial = rss_kb(a.pid)
+        fir = threading.Thread(target=urmareste_memoria, args=(a.pid, rezultat, gata))
+        fir.start()
+
+    aruncate_kernel = erori_buffer_udp()
+    s = socket.socket(socket.AF_INET, socket.SOCK_DGRAM)
+    inceput = time.time()
+    for i, m in enumerate(mesaje):
+        s.sendto(m, (a.host, a.port))
+        # Cu --rata, așteptăm după fiecare pachet de 1000 de mesaje
+        if a.rata and i % 1000 == 999:
+            intarziere = inceput + (i + 1) / a.rata - time.time()
+            if intarziere > 0:
+                time.sleep(intarziere)
+    durata = time.time() - inceput
+
+    print(f"Trimise: {a.mesaje} mesaje de la {a.surse} surse în {durata:.2f}s "
+          f"({a.mesaje / durata:,.0f} mesaje/s)")
+
+    if a.pid:
+        # Lăsăm scanner-ul să termine ce are în cozi
+        time.sleep(3)
+        gata.set()
+        fir.join()
+        print(f"Memorie (RSS): {rss_initial / 1024:.1f} MB la început, "
+              f"maxim {rezultat['rss_maxim'] / 1024:.1f} MB, "
+              f"{rss_kb(a.pid) / 1024:.1f} MB la final")
+
+    print(f"Aruncate de kernel (buffer UDP plin): {erori_buffer_udp() - aruncate_kernel}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,56 @@
+// ============================================================================
+// Benchmark-uri pentru parserul de log-uri (Criterion)
+// ============================================================================
+// Rulare (din directorul cu Cargo.toml):
+//   cargo bench --bench parsare
+//
+// Raportul arată timpul per linie și linii/secundă ("thrpt") pentru:
+// - o linie CEF tipică (cu header Syslog, rt, câmpuri personalizate)
+// - o linie syslog simplă (fallback-ul src=/dpt=)
+// - o linie nerecunoscută (trece prin tot lanțul de formate - cazul cel mai lent)
+// ============================================================================
+
+use criterion::{criterion_group, criterion_main};
+
+// Programul e un singur fișier fără bibliotecă; îl includem ca modul, ca
+// benchmark-ul să poată folosi ParsorLoguri direct (tipurile sunt private)
+mod ids_scanner {
+    #![allow(dead_code, unused_imports)]
+
+    include!("../main.rs");
+
+    use criterion::{BenchmarkId, Criterion, Throughput};
+    use std::hint::black_box;
+
+    const LINIE_CEF: &str = "<134>Jan 15 10:30:45 firewall CEF:0|Palo Alto Networks|PAN-OS|10.1|TRAFFIC|Traffic Denied|5|rt=Jan 15 2024 10:30:45 src=192.168.1.100 dst=10.0.0.50 spt=51234 dpt=22 proto=TCP act=deny dvchost=fw-01 cs1Label=Rule cs1=Block SSH msg=Connection denied by rule 5";
+    const LINIE_SYSLOG: &str = "<13>Jan 15 10:30:45 gw kernel: SRC=192.168.1.100 DST=10.0.0.50 DPT=22 ACT=deny";
+    const LINIE_NERECUNOSCUTA: &str = "<30>Jan 15 10:30:45 web01 systemd[1]: Started Session 42 of user root.";
+
+    pub fn parsare(c: &mut Criterion) {
+        let mut grup = c.benchmark_group("parsare");
+        // Throughput::Elements(1) = raportul arată și linii/secundă
+        grup.throughput(Throughput::Elements(1));
+
+        for pastreaza_raw in [false, true] {
+            let config = ConfigurareParsoare { pastreaza_raw, ..Default::default() };
+            let parsor = ParsorLoguri::din_configurare(&config).expect("lanțul implicit de parsere");
+            let varianta = if pastreaza_raw { "cu_raw" } else { "fara_raw" };
+
+            for (nume, linie) in [
+                ("cef", LINIE_CEF),
+                ("syslog", LINIE_SYSLOG),
+                ("nerecunoscuta", LINIE_NERECUNOSCUTA),
+            ] {
+                // black_box() = împiedică compilatorul să elimine calculul
+                grup.bench_with_input(BenchmarkId::new(nume, varianta), linie, |b, linie| {
+                    b.iter(|| black_box(parsor.parseaza(black_box(linie)).is_ok()))
+                });
+            }
+        }
+
+        grup.finish();
+    }
+}
+
+criterion_group!(benchmarks, ids_scanner::parsare);
+criterion_main!(benchmarks);